@@ -0,0 +1,69 @@
+//! Cross-process span context for distributed tracing.
+
+/// A trace identifier, shared by every span belonging to the same trace.
+///
+/// This mirrors the 16-byte trace-id carried by a W3C `traceparent` header.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceId([u8; 16]);
+
+/// The propagated context of a span created in another process.
+///
+/// Unlike an [`Id`], which only identifies a span within the context of a
+/// single [`Subscriber`](::Subscriber), a `SpanContext` carries the minimum
+/// information needed to link a locally-created span to a parent span living in
+/// another process: the [`TraceId`] shared across the whole trace, the parent
+/// span's raw 8-byte span-id, and a flags/sampling byte. A subscriber receiving
+/// a deserialized upstream context (for example parsed from a W3C `traceparent`
+/// header) can root a new span under it while still assigning a fresh local
+/// [`Id`](::span::Id).
+///
+/// The parent span-id is kept as its raw 8-byte representation rather than a
+/// local [`Id`](::span::Id), since it identifies a span in another process and
+/// mirrors the 8-byte span-id carried by a W3C `traceparent` header.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpanContext {
+    trace_id: TraceId,
+    parent_id: [u8; 8],
+    flags: u8,
+}
+
+impl TraceId {
+    /// Constructs a `TraceId` from its 16-byte representation.
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        TraceId(bytes)
+    }
+
+    /// Returns the 16-byte representation of this trace ID.
+    pub fn into_bytes(&self) -> [u8; 16] {
+        self.0
+    }
+}
+
+impl SpanContext {
+    /// Constructs a new `SpanContext` from a trace ID, the raw 8-byte parent
+    /// span-id, and a flags byte.
+    pub fn new(trace_id: TraceId, parent_id: [u8; 8], flags: u8) -> Self {
+        SpanContext {
+            trace_id,
+            parent_id,
+            flags,
+        }
+    }
+
+    /// Returns the ID of the trace this context belongs to.
+    pub fn trace_id(&self) -> &TraceId {
+        &self.trace_id
+    }
+
+    /// Returns the raw 8-byte span-id of the remote parent span.
+    pub fn parent_id(&self) -> [u8; 8] {
+        self.parent_id
+    }
+
+    /// Returns the flags/sampling byte propagated with this context.
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+}
@@ -0,0 +1,95 @@
+//! Dispatches trace data to the current [`Subscriber`](::Subscriber).
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use {field, span, Event, Metadata, Subscriber};
+
+/// `Dispatch` trace data to a [`Subscriber`](::Subscriber).
+#[derive(Clone)]
+pub struct Dispatch {
+    subscriber: Arc<Subscriber + Send + Sync>,
+}
+
+struct NoSubscriber;
+
+thread_local! {
+    static CURRENT: RefCell<Dispatch> = RefCell::new(Dispatch::none());
+}
+
+impl Dispatch {
+    /// Returns a new `Dispatch` that discards all trace data.
+    pub fn none() -> Self {
+        Dispatch {
+            subscriber: Arc::new(NoSubscriber),
+        }
+    }
+
+    /// Returns a `Dispatch` that forwards to the given `subscriber`.
+    pub fn new<S>(subscriber: S) -> Self
+    where
+        S: Subscriber + Send + Sync + 'static,
+    {
+        Dispatch {
+            subscriber: Arc::new(subscriber),
+        }
+    }
+
+    /// Registers a new span with the current subscriber, returning its ID.
+    pub fn new_span(&self, span: &span::Attributes) -> span::Id {
+        self.subscriber.new_span(span)
+    }
+
+    /// Records that an event has occurred with the current subscriber.
+    pub fn event(&self, event: &Event) {
+        self.subscriber.event(event)
+    }
+
+    /// Records that a span has been entered with the current subscriber.
+    pub fn enter(&self, span: &span::Id) {
+        self.subscriber.enter(span)
+    }
+
+    /// Records that a span has been exited with the current subscriber.
+    pub fn exit(&self, span: &span::Id) {
+        self.subscriber.exit(span)
+    }
+}
+
+impl Subscriber for NoSubscriber {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        false
+    }
+
+    fn new_span(&self, _span: &span::Attributes) -> span::Id {
+        span::Id::from_u64(0xDEAD)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &field::ValueSet) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event) {}
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+/// Executes a closure with a reference to the current dispatcher.
+pub fn get_default<T, F>(mut f: F) -> T
+where
+    F: FnMut(&Dispatch) -> T,
+{
+    CURRENT.with(|current| f(&*current.borrow()))
+}
+
+/// Sets this dispatch as the default for the duration of a closure.
+pub fn with_default<T>(dispatch: &Dispatch, f: impl FnOnce() -> T) -> T {
+    let prev = CURRENT.with(|current| current.replace(dispatch.clone()));
+    let result = f();
+    CURRENT.with(|current| {
+        *current.borrow_mut() = prev;
+    });
+    result
+}
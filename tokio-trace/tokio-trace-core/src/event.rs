@@ -0,0 +1,112 @@
+//! Events represent single points in time during the execution of a program.
+
+use parent::Parent;
+use span::Id;
+use {dispatcher, field, Metadata};
+
+/// `Event`s represent single points in time where something occurred during the
+/// execution of a program.
+///
+/// An `Event` can be compared to a log record in unstructured logging, but with
+/// two key differences: `Event`s exist _within the context of a [span]_, and
+/// like spans, they may have [fields] that describe the occurrence. Events may
+/// be located in the trace tree either as the child of the current span, the
+/// child of an explicitly-specified span, or as a root with no parent.
+///
+/// [span]: ::span
+/// [fields]: ::field
+#[derive(Debug)]
+pub struct Event<'a> {
+    fields: &'a field::ValueSet<'a>,
+    metadata: &'a Metadata<'a>,
+    parent: Parent,
+}
+
+impl<'a> Event<'a> {
+    /// Returns a new `Event` in the current span, with the specified metadata
+    /// and set of values.
+    pub fn new(metadata: &'a Metadata<'a>, fields: &'a field::ValueSet<'a>) -> Self {
+        Event {
+            fields,
+            metadata,
+            parent: Parent::Current,
+        }
+    }
+
+    /// Returns a new `Event` at the root of its own trace tree, with the
+    /// specified metadata and set of values.
+    pub fn new_root(metadata: &'a Metadata<'a>, fields: &'a field::ValueSet<'a>) -> Self {
+        Event {
+            fields,
+            metadata,
+            parent: Parent::Root,
+        }
+    }
+
+    /// Returns a new `Event` as a child of the specified span, with the
+    /// provided metadata and set of values.
+    pub fn new_child_of(
+        parent: Id,
+        metadata: &'a Metadata<'a>,
+        fields: &'a field::ValueSet<'a>,
+    ) -> Self {
+        Event {
+            fields,
+            metadata,
+            parent: Parent::Explicit(parent),
+        }
+    }
+
+    /// Constructs a new `Event` with the specified metadata and set of values,
+    /// and observes it with the current subscriber.
+    pub fn dispatch(metadata: &'a Metadata<'a>, fields: &'a field::ValueSet<'a>) {
+        let event = Event::new(metadata, fields);
+        dispatcher::get_default(|current| {
+            current.event(&event);
+        });
+    }
+
+    /// Returns a reference to the event's metadata.
+    pub fn metadata(&self) -> &Metadata<'a> {
+        self.metadata
+    }
+
+    /// Returns a reference to a `ValueSet` containing any values the event was
+    /// created with.
+    pub fn fields(&self) -> &field::ValueSet<'a> {
+        self.fields
+    }
+
+    /// Returns true if the new event should be a root.
+    pub fn is_root(&self) -> bool {
+        match self.parent {
+            Parent::Root => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the new event's parent should be determined based on the
+    /// current context.
+    ///
+    /// If this is true and the current thread is currently inside a span, then
+    /// that span should be the new event's parent. Otherwise, if the current
+    /// thread is _not_ inside a span, then the new event will be the root of
+    /// its own trace tree.
+    pub fn is_contextual(&self) -> bool {
+        match self.parent {
+            Parent::Current => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the new event's explicitly-specified parent, if there is one.
+    ///
+    /// Otherwise (if the new event is a root or is a child of the current
+    /// span), returns `None`.
+    pub fn parent(&self) -> Option<&Id> {
+        match self.parent {
+            Parent::Explicit(ref p) => Some(p),
+            _ => None,
+        }
+    }
+}
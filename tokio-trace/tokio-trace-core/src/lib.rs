@@ -0,0 +1,27 @@
+//! Core primitives for `tokio-trace`.
+//!
+//! This crate defines the core traits and types that make up `tokio-trace`'s
+//! instrumentation system: spans, events, the metadata that describes them, the
+//! fields they carry, and the `Subscriber` trait that collects them.
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
+pub mod dispatcher;
+pub mod event;
+pub mod field;
+pub mod metadata;
+pub mod span;
+pub mod subscriber;
+
+mod context;
+mod parent;
+
+#[cfg(any(test, feature = "test-support"))]
+pub mod support;
+
+pub use context::{SpanContext, TraceId};
+pub use dispatcher::Dispatch;
+pub use event::Event;
+pub use metadata::{Level, Metadata};
+pub use subscriber::Subscriber;
@@ -0,0 +1,27 @@
+//! Shared description of how a newly-created span or event is parented.
+
+use context::SpanContext;
+use span::Id;
+
+/// Describes the parent of a new span or event.
+///
+/// This is shared between [`span::Attributes`](::span::Attributes) and
+/// [`Event`](::event::Event), which both need to describe whether the thing
+/// they create is a root, a child of the current span, or a child of an
+/// explicitly-specified span.
+#[derive(Debug)]
+pub(crate) enum Parent {
+    /// The new span or event will be a root.
+    Root,
+    /// The new span or event will be rooted in the current span.
+    Current,
+    /// The new span or event has an explicitly-specified parent.
+    Explicit(Id),
+    /// The new span is a child of a span propagated from another process.
+    ///
+    /// This variant is only ever produced by
+    /// [`Attributes::child_of_remote`](::span::Attributes::child_of_remote);
+    /// events cannot have remote parents, so [`Event`](::event::Event) never
+    /// constructs it.
+    Remote(SpanContext),
+}
@@ -1,5 +1,9 @@
 //! Spans represent periods of time in the execution of a program.
 
+use std::num::NonZeroU64;
+
+use context::SpanContext;
+use parent::Parent;
 use {field, Metadata};
 
 /// Identifies a span within the context of a subscriber.
@@ -8,8 +12,14 @@ use {field, Metadata};
 /// created, by the [`new_span`](::Subscriber::new_span) trait
 /// method. See the documentation for that method for more information on span
 /// ID generation.
+///
+/// The ID `0` is reserved and is never a valid span ID: `Id` wraps a
+/// [`NonZeroU64`], so that `Option<Id>` is the same size as `Id`. Subscribers
+/// must not hand out `0` as a span ID.
+///
+/// [`NonZeroU64`]: std::num::NonZeroU64
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Id(u64);
+pub struct Id(NonZeroU64);
 
 /// Attributes provided to a `Subscriber` describing a new span when it is
 /// created.
@@ -20,27 +30,35 @@ pub struct Attributes<'a> {
     parent: Parent,
 }
 
-#[derive(Debug)]
-enum Parent {
-    /// The new span will be a root span.
-    Root,
-    /// The new span will be rooted in the current span.
-    Current,
-    /// The new span has an explicitly-specified parent.
-    Explicit(Id),
-}
-
 // ===== impl Span =====
 
 impl Id {
     /// Constructs a new span ID from the given `u64`.
+    ///
+    /// The span ID `0` is reserved, so this function will panic if passed `0`.
+    /// Subscribers that may produce a `0` ID should use
+    /// [`try_from_u64`](Id::try_from_u64) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u == 0`.
     pub fn from_u64(u: u64) -> Self {
-        Id(u)
+        Id(NonZeroU64::new(u).expect("span IDs must be greater than 0"))
+    }
+
+    /// Constructs a new span ID from the given `u64`, returning `None` if `u`
+    /// is `0`.
+    ///
+    /// Since the span ID `0` is reserved, this is the fallible counterpart to
+    /// [`from_u64`](Id::from_u64) for subscribers that cannot guarantee a
+    /// non-zero ID up front.
+    pub fn try_from_u64(u: u64) -> Option<Self> {
+        NonZeroU64::new(u).map(Id)
     }
 
     /// Returns the span's ID as a  `u64`.
     pub fn into_u64(&self) -> u64 {
-        self.0
+        self.0.get()
     }
 }
 
@@ -81,6 +99,24 @@ impl<'a> Attributes<'a> {
         }
     }
 
+    /// Returns `Attributes` describing a new child span of a span propagated
+    /// from another process, with the provided metadata and values.
+    ///
+    /// The new span is assigned a fresh local `Id` by the subscriber, but is
+    /// rooted under the remote parent carried by `ctx`, allowing a trace to be
+    /// continued across a service boundary.
+    pub fn child_of_remote(
+        ctx: SpanContext,
+        metadata: &'a Metadata<'a>,
+        values: &'a field::ValueSet<'a>,
+    ) -> Self {
+        Attributes {
+            metadata,
+            values,
+            parent: Parent::Remote(ctx),
+        }
+    }
+
     /// Returns a reference to the new span's metadata.
     pub fn metadata(&self) -> &Metadata<'a> {
         self.metadata
@@ -124,4 +160,16 @@ impl<'a> Attributes<'a> {
             _ => None,
         }
     }
-}
\ No newline at end of file
+
+    /// Returns the new span's remote parent context, if it was created as a
+    /// child of a span propagated from another process.
+    ///
+    /// Otherwise (if the new span is a root, a child of the current span, or a
+    /// child of an explicit local parent), returns `None`.
+    pub fn remote_parent(&self) -> Option<&SpanContext> {
+        match self.parent {
+            Parent::Remote(ref ctx) => Some(ctx),
+            _ => None,
+        }
+    }
+}
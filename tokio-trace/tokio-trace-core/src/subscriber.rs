@@ -0,0 +1,53 @@
+//! Collectors of trace data.
+
+use {field, span, Event, Metadata};
+
+/// Trait representing the functions required to collect trace data.
+///
+/// Crates that collect or record trace data do so by implementing the
+/// `Subscriber` trait and installing a `Subscriber` as the current default;
+/// instrumentation then reports its spans and events to that default
+/// subscriber through the [`dispatcher`](::dispatcher). A subscriber is notified as spans are
+/// created, entered, exited, and closed, as events are recorded, and as values
+/// are recorded on spans.
+pub trait Subscriber {
+    /// Returns true if a span or event with the specified `metadata` would be
+    /// recorded.
+    fn enabled(&self, metadata: &Metadata) -> bool;
+
+    /// Visit the construction of a new span, returning a new [span ID] for the
+    /// span being constructed.
+    ///
+    /// [span ID]: ::span::Id
+    fn new_span(&self, span: &span::Attributes) -> span::Id;
+
+    /// Record a set of values on a span.
+    fn record(&self, span: &span::Id, values: &field::ValueSet);
+
+    /// Adds an indication that `span` follows from the span with the id
+    /// `follows`.
+    fn record_follows_from(&self, span: &span::Id, follows: &span::Id);
+
+    /// Records that an [`Event`] has occurred.
+    ///
+    /// [`Event`]: ::event::Event
+    fn event(&self, event: &Event);
+
+    /// Records that a span has been entered.
+    fn enter(&self, span: &span::Id);
+
+    /// Records that a span has been exited.
+    fn exit(&self, span: &span::Id);
+
+    /// Notifies the subscriber that a span ID has been cloned.
+    ///
+    /// By default, this does nothing and returns the provided ID.
+    fn clone_span(&self, id: &span::Id) -> span::Id {
+        id.clone()
+    }
+
+    /// Notifies the subscriber that a span ID has been dropped.
+    ///
+    /// By default, this does nothing.
+    fn drop_span(&self, _id: span::Id) {}
+}
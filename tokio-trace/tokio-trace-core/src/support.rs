@@ -0,0 +1,335 @@
+//! A mock [`Subscriber`](::Subscriber) for testing instrumentation.
+//!
+//! This module provides a builder-style [`MockSpan`] for describing a span one
+//! expects to be created, together with a [`MockSubscriber`] that is driven by
+//! an ordered script of expected operations. When an incoming span or lifecycle
+//! event fails to match the next expectation, the subscriber panics with a
+//! descriptive diff, so downstream crates can unit-test their instrumentation
+//! without hand-rolling a [`Subscriber`](::Subscriber) each time.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use event::Event;
+use span::{Attributes, Id};
+use {field, Level, Metadata, Subscriber};
+
+/// A description of a span to match an incoming span against.
+///
+/// Construct one with [`mock`] and refine it with the fluent matchers
+/// [`named`](MockSpan::named), [`at_level`](MockSpan::at_level),
+/// [`with_target`](MockSpan::with_target), and
+/// [`with_field`](MockSpan::with_field). Any matcher left unset matches any
+/// value.
+#[derive(Clone, Debug, Default)]
+pub struct MockSpan {
+    name: Option<String>,
+    level: Option<Level>,
+    target: Option<String>,
+    fields: Vec<String>,
+}
+
+/// Describes the parent a new span is expected to have.
+#[derive(Clone, Debug)]
+enum MockParent {
+    /// The new span is expected to be an explicit child of a named parent.
+    Named(String),
+    /// The new span is expected to inherit the contextual current span.
+    Contextual,
+    /// The new span is expected to be an explicit root.
+    Root,
+}
+
+/// An expectation that a span matching [`MockSpan`] is created, optionally with
+/// a particular parent.
+#[derive(Clone, Debug)]
+pub struct NewSpan {
+    span: MockSpan,
+    parent: Option<MockParent>,
+}
+
+#[derive(Clone, Debug)]
+enum Expect {
+    NewSpan(NewSpan),
+    Event(MockSpan),
+    Enter(MockSpan),
+    Exit(MockSpan),
+    CloseSpan(MockSpan),
+}
+
+/// Returns a new, empty [`MockSpan`] that matches any span.
+pub fn mock() -> MockSpan {
+    MockSpan::default()
+}
+
+impl MockSpan {
+    /// Expect the span to have the given name.
+    pub fn named<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Expect the span to be recorded at the given level.
+    pub fn at_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Expect the span to have the given target.
+    pub fn with_target<S: Into<String>>(mut self, target: S) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Expect the span to be created with a field of the given name.
+    pub fn with_field<S: Into<String>>(mut self, field: S) -> Self {
+        self.fields.push(field.into());
+        self
+    }
+
+    /// Promote this `MockSpan` into a [`NewSpan`] expectation with no parent
+    /// constraint.
+    pub fn new_span(self) -> NewSpan {
+        NewSpan {
+            span: self,
+            parent: None,
+        }
+    }
+
+    fn check(&self, metadata: &Metadata) {
+        if let Some(ref name) = self.name {
+            assert!(
+                name == metadata.name(),
+                "expected span named `{}`, but got `{}`",
+                name,
+                metadata.name()
+            );
+        }
+        if let Some(level) = self.level {
+            assert!(
+                &level == metadata.level(),
+                "expected span `{}` at level {:?}, but got {:?}",
+                metadata.name(),
+                level,
+                metadata.level()
+            );
+        }
+        if let Some(ref target) = self.target {
+            assert!(
+                target == metadata.target(),
+                "expected span `{}` with target `{}`, but got `{}`",
+                metadata.name(),
+                target,
+                metadata.target()
+            );
+        }
+        for field in &self.fields {
+            assert!(
+                metadata.fields().field(field).is_some(),
+                "expected span `{}` to have field `{}`, but it did not",
+                metadata.name(),
+                field
+            );
+        }
+    }
+}
+
+impl NewSpan {
+    /// Expect the new span to be an explicit child of a span with the given
+    /// name.
+    pub fn with_explicit_parent<S: Into<String>>(mut self, parent: S) -> Self {
+        self.parent = Some(MockParent::Named(parent.into()));
+        self
+    }
+
+    /// Expect the new span's parent to be the contextual current span.
+    pub fn with_contextual_parent(mut self) -> Self {
+        self.parent = Some(MockParent::Contextual);
+        self
+    }
+
+    /// Expect the new span to be an explicit root.
+    pub fn with_explicit_root(mut self) -> Self {
+        self.parent = Some(MockParent::Root);
+        self
+    }
+
+    fn check(&self, attrs: &Attributes, spans: &HashMap<u64, String>) {
+        self.span.check(attrs.metadata());
+        match self.parent {
+            Some(MockParent::Root) => assert!(
+                attrs.is_root(),
+                "expected span `{}` to be an explicit root, but it was not",
+                attrs.metadata().name()
+            ),
+            Some(MockParent::Contextual) => assert!(
+                attrs.is_contextual(),
+                "expected span `{}` to have a contextual parent, but it did not",
+                attrs.metadata().name()
+            ),
+            Some(MockParent::Named(ref name)) => {
+                let parent = attrs.parent().expect("expected an explicit parent");
+                let actual = spans
+                    .get(&parent.into_u64())
+                    .expect("explicit parent was never recorded as a new span");
+                assert!(
+                    actual == name,
+                    "expected span `{}` to be a child of `{}`, but its parent was `{}`",
+                    attrs.metadata().name(),
+                    name,
+                    actual
+                );
+            }
+            None => {}
+        }
+    }
+}
+
+/// Builds a [`MockSubscriber`] from an ordered script of expectations.
+#[derive(Default)]
+pub struct MockSubscriberBuilder {
+    expected: VecDeque<Expect>,
+}
+
+/// A [`Subscriber`](::Subscriber) that checks each operation against an ordered
+/// script of expectations, panicking with a descriptive diff on the first
+/// mismatch.
+pub struct MockSubscriber {
+    expected: Mutex<VecDeque<Expect>>,
+    spans: Mutex<HashMap<u64, String>>,
+    next_id: Mutex<u64>,
+}
+
+/// Returns a new, empty [`MockSubscriberBuilder`].
+pub fn subscriber() -> MockSubscriberBuilder {
+    MockSubscriberBuilder::default()
+}
+
+impl MockSubscriberBuilder {
+    /// Expect a matching span to be created next.
+    pub fn new_span<I: Into<NewSpan>>(mut self, span: I) -> Self {
+        self.expected.push_back(Expect::NewSpan(span.into()));
+        self
+    }
+
+    /// Expect a matching event to be recorded next.
+    ///
+    /// The event's metadata is checked against `event` using the same matchers
+    /// as a span.
+    pub fn event(mut self, event: MockSpan) -> Self {
+        self.expected.push_back(Expect::Event(event));
+        self
+    }
+
+    /// Expect a matching span to be entered next.
+    pub fn enter(mut self, span: MockSpan) -> Self {
+        self.expected.push_back(Expect::Enter(span));
+        self
+    }
+
+    /// Expect a matching span to be exited next.
+    pub fn exit(mut self, span: MockSpan) -> Self {
+        self.expected.push_back(Expect::Exit(span));
+        self
+    }
+
+    /// Expect a matching span to be closed next.
+    pub fn close(mut self, span: MockSpan) -> Self {
+        self.expected.push_back(Expect::CloseSpan(span));
+        self
+    }
+
+    /// Finish building, returning the [`MockSubscriber`].
+    pub fn run(self) -> MockSubscriber {
+        MockSubscriber {
+            expected: Mutex::new(self.expected),
+            spans: Mutex::new(HashMap::new()),
+            next_id: Mutex::new(1),
+        }
+    }
+}
+
+impl From<MockSpan> for NewSpan {
+    fn from(span: MockSpan) -> Self {
+        span.new_span()
+    }
+}
+
+impl MockSubscriber {
+    fn pop(&self) -> Option<Expect> {
+        self.expected.lock().unwrap().pop_front()
+    }
+
+    fn check_lifecycle(&self, id: &Id, expected: &MockSpan, op: &str) {
+        let spans = self.spans.lock().unwrap();
+        let name = spans
+            .get(&id.into_u64())
+            .unwrap_or_else(|| panic!("tried to {} a span that was never created", op));
+        if let Some(ref expected_name) = expected.name {
+            assert!(
+                expected_name == name,
+                "expected to {} span `{}`, but {} `{}`",
+                op,
+                expected_name,
+                op,
+                name
+            );
+        }
+    }
+}
+
+impl Subscriber for MockSubscriber {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes) -> Id {
+        match self.pop() {
+            Some(Expect::NewSpan(expected)) => {
+                let spans = self.spans.lock().unwrap();
+                expected.check(span, &spans);
+            }
+            other => panic!("expected {:?}, but got a new span", other),
+        }
+        let mut next = self.next_id.lock().unwrap();
+        let id = *next;
+        *next += 1;
+        self.spans
+            .lock()
+            .unwrap()
+            .insert(id, span.metadata().name().to_string());
+        Id::from_u64(id)
+    }
+
+    fn record(&self, _span: &Id, _values: &field::ValueSet) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event) {
+        match self.pop() {
+            Some(Expect::Event(expected)) => expected.check(event.metadata()),
+            other => panic!("expected {:?}, but got an event", other),
+        }
+    }
+
+    fn enter(&self, span: &Id) {
+        match self.pop() {
+            Some(Expect::Enter(expected)) => self.check_lifecycle(span, &expected, "enter"),
+            other => panic!("expected {:?}, but entered a span", other),
+        }
+    }
+
+    fn exit(&self, span: &Id) {
+        match self.pop() {
+            Some(Expect::Exit(expected)) => self.check_lifecycle(span, &expected, "exit"),
+            other => panic!("expected {:?}, but exited a span", other),
+        }
+    }
+
+    fn drop_span(&self, span: Id) {
+        match self.pop() {
+            Some(Expect::CloseSpan(expected)) => self.check_lifecycle(&span, &expected, "close"),
+            other => panic!("expected {:?}, but closed a span", other),
+        }
+    }
+}